@@ -13,6 +13,8 @@ Jane Smith can be reached at jane.smith@company.org or (800) 555-1212.";
     test_policy(text, PrivacyPolicy::Redact);
     test_policy(text, PrivacyPolicy::Anonymize);
     test_policy(text, PrivacyPolicy::Hash);
+    test_policy(text, PrivacyPolicy::Mask);
+    test_policy(text, PrivacyPolicy::Tokenize);
 }
 
 fn test_policy(text: &str, policy: PrivacyPolicy) {
@@ -20,6 +22,8 @@ fn test_policy(text: &str, policy: PrivacyPolicy) {
         PrivacyPolicy::Redact => "REDACT",
         PrivacyPolicy::Anonymize => "ANONYMIZE",
         PrivacyPolicy::Hash => "HASH",
+        PrivacyPolicy::Mask => "MASK",
+        PrivacyPolicy::Tokenize => "TOKENIZE",
     };
     
     println!("\n============ Testing {} policy ============", policy_name);
@@ -34,6 +38,7 @@ fn test_policy(text: &str, policy: PrivacyPolicy) {
             "CREDIT_CARD".to_string(),
         ],
         priv_policy: policy,
+        ..Default::default()
     };
     
     // Process the request